@@ -8,7 +8,7 @@ use core::panic::PanicInfo;
 
 #[panic_handler]
 fn panic(_info: &PanicInfo) -> ! {
-    loop {}
+    core::arch::wasm32::unreachable()
 }
 
 #[no_mangle]
@@ -26,7 +26,7 @@ pub extern "C" fn fact(n: u64) -> u64 {
     if n == 1 {
         return 1;
     }
-    n * fact(n - 1)
+    n.checked_mul(fact(n - 1)).expect("fact overflow")
 }
 
 #[no_mangle]
@@ -0,0 +1,26 @@
+/*
+   rustc -O --target wasm32-wasi --crate-type cdylib -C link-arg=--strip-debug -Cpanic=abort $%
+
+   Unlike the other fixtures in this directory, this module declares
+   imports and therefore only loads when the host grants it the
+   corresponding capability.
+*/
+
+#![no_std]
+
+use core::panic::PanicInfo;
+
+#[panic_handler]
+fn panic(_info: &PanicInfo) -> ! {
+    core::arch::wasm32::unreachable()
+}
+
+#[link(wasm_import_module = "env")]
+extern "C" {
+    fn host_log(ptr: *const u8, len: u32);
+}
+
+#[no_mangle]
+pub extern "C" fn greet(ptr: *const u8, len: u32) {
+    unsafe { host_log(ptr, len) }
+}
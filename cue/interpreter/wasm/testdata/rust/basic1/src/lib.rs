@@ -13,7 +13,7 @@ pub extern "C" fn fact(n: u64) -> u64 {
     if n == 1 {
         return 1;
     }
-    n * fact(n - 1)
+    n.checked_mul(fact(n - 1)).expect("fact overflow")
 }
 
 #[no_mangle]
@@ -0,0 +1,38 @@
+/*
+   A variant of ../strings that advertises the single cabi_realloc
+   convention instead of the allocate/deallocate pair exported by
+   ../strings/src/mem.rs, to exercise allocator-export negotiation.
+*/
+
+#[no_mangle]
+pub extern "C" fn cabi_realloc(
+    old_ptr: *mut u8,
+    old_len: usize,
+    align: usize,
+    new_len: usize,
+) -> *mut u8 {
+    unsafe {
+        if new_len == 0 {
+            return align as *mut u8;
+        }
+        let layout = std::alloc::Layout::from_size_align(new_len, align).unwrap();
+        if old_ptr.is_null() {
+            std::alloc::alloc(layout)
+        } else {
+            let old_layout = std::alloc::Layout::from_size_align(old_len, align).unwrap();
+            std::alloc::realloc(old_ptr, old_layout, new_len)
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn concat(a_ptr: *const u8, a_len: u32, b_ptr: *const u8, b_len: u32) -> u32 {
+    let a = unsafe { std::slice::from_raw_parts(a_ptr, a_len as usize) };
+    let b = unsafe { std::slice::from_raw_parts(b_ptr, b_len as usize) };
+    let mut out = Vec::with_capacity(a.len() + b.len());
+    out.extend_from_slice(a);
+    out.extend_from_slice(b);
+    let ptr = cabi_realloc(std::ptr::null_mut(), 0, 1, out.len());
+    unsafe { std::ptr::copy_nonoverlapping(out.as_ptr(), ptr, out.len()) };
+    ptr as u32
+}
@@ -0,0 +1,68 @@
+mod mem;
+
+#[no_mangle]
+pub extern "C" fn str_len(ptr: *const u8, len: u32) -> u32 {
+    let s = unsafe { std::slice::from_raw_parts(ptr, len as usize) };
+    s.len() as u32
+}
+
+#[no_mangle]
+pub extern "C" fn count_vowels(ptr: *const u8, len: u32) -> u32 {
+    let bytes = unsafe { std::slice::from_raw_parts(ptr, len as usize) };
+    let s = unsafe { std::str::from_utf8_unchecked(bytes) };
+    s.chars()
+        .filter(|c| "aeiouAEIOU".contains(*c))
+        .count() as u32
+}
+
+#[no_mangle]
+pub extern "C" fn sum_f64(ptr: *const f64, len: u32) -> f64 {
+    let xs = unsafe { std::slice::from_raw_parts(ptr, len as usize) };
+    xs.iter().sum()
+}
+
+#[no_mangle]
+pub extern "C" fn max_i64(ptr: *const i64, len: u32) -> i64 {
+    let xs = unsafe { std::slice::from_raw_parts(ptr, len as usize) };
+    xs.iter().copied().max().unwrap_or(0)
+}
+
+#[repr(C)]
+pub struct ListReturn {
+    ptr: *mut u8,
+    len: u32,
+}
+
+fn write_return(bytes: Vec<u8>, ret: *mut ListReturn) {
+    let mut bytes = bytes.into_boxed_slice();
+    let ptr = bytes.as_mut_ptr();
+    let len = bytes.len() as u32;
+    std::mem::forget(bytes);
+    unsafe {
+        (*ret).ptr = ptr;
+        (*ret).len = len;
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn concat(
+    a_ptr: *const u8,
+    a_len: u32,
+    b_ptr: *const u8,
+    b_len: u32,
+    ret: *mut ListReturn,
+) {
+    let a = unsafe { std::slice::from_raw_parts(a_ptr, a_len as usize) };
+    let b = unsafe { std::slice::from_raw_parts(b_ptr, b_len as usize) };
+    let mut out = Vec::with_capacity(a.len() + b.len());
+    out.extend_from_slice(a);
+    out.extend_from_slice(b);
+    write_return(out, ret);
+}
+
+#[no_mangle]
+pub extern "C" fn to_upper(ptr: *const u8, len: u32, ret: *mut ListReturn) {
+    let bytes = unsafe { std::slice::from_raw_parts(ptr, len as usize) };
+    let s = unsafe { std::str::from_utf8_unchecked(bytes) };
+    write_return(s.to_uppercase().into_bytes(), ret);
+}
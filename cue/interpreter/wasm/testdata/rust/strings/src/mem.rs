@@ -0,0 +1,31 @@
+extern crate alloc;
+extern crate core;
+extern crate wee_alloc;
+
+use alloc::vec::Vec;
+use std::mem::MaybeUninit;
+
+#[global_allocator]
+static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
+
+#[cfg_attr(all(target_arch = "wasm32"), export_name = "allocate")]
+#[no_mangle]
+pub extern "C" fn _allocate(size: u32) -> *mut u8 {
+    allocate(size as usize)
+}
+
+fn allocate(size: usize) -> *mut u8 {
+    let vec: Vec<MaybeUninit<u8>> = Vec::with_capacity(size);
+
+    Box::into_raw(vec.into_boxed_slice()) as *mut u8
+}
+
+#[cfg_attr(all(target_arch = "wasm32"), export_name = "deallocate")]
+#[no_mangle]
+pub unsafe extern "C" fn _deallocate(ptr: u32, size: u32) {
+    deallocate(ptr as *mut u8, size as usize);
+}
+
+unsafe fn deallocate(ptr: *mut u8, size: usize) {
+    let _ = Vec::from_raw_parts(ptr, 0, size);
+}